@@ -1,7 +1,18 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+#[cfg(feature = "rand")]
+use rand::RngCore;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+#[cfg(feature = "serde")]
+pub use serde_support::{MacAddressDot, MacAddressEui, MacAddressHex, MacAddressRaw};
+
 #[derive(Debug, PartialEq)]
 pub enum MacAddressError {
     InvalidLength(String),
     InvalidMac(String),
+    NotMulticast(String),
 }
 
 impl std::fmt::Display for MacAddressError {
@@ -11,14 +22,52 @@ impl std::fmt::Display for MacAddressError {
                 write!(f, "address: `{a}` is not 12 characters long")
             }
             MacAddressError::InvalidMac(a) => write!(f, "address: `{a}` is not a MAC adddress"),
+            MacAddressError::NotMulticast(a) => write!(f, "address: `{a}` is not a multicast IP"),
         }
     }
 }
 
-#[derive(Debug)]
+/// A MAC-48/EUI-48 address, stored as a packed `[u8; 6]`.
+///
+/// `MacAddress` is `Copy` and cheaply comparable/hashable, so it is fine to
+/// hold by value in lookup tables, eg a switch forwarding database keyed by
+/// `HashMap<MacAddress, _>`.
+///
+/// An address parsed via [`MacAddress::parse_tagged`] may carry an 802.1Q
+/// VLAN ID alongside the address itself; see [`MacAddress::vlan`]. Equality,
+/// ordering, and hashing only ever consider the address octets, not the
+/// VLAN tag, so addresses keep comparing equal across VLANs the way a
+/// forwarding database would expect.
+#[derive(Debug, Clone, Copy)]
 pub struct MacAddress {
-    eui48: Vec<u8>,
-    eui64: Vec<u8>,
+    octets: [u8; 6],
+    vlan: Option<u16>,
+}
+
+impl PartialEq for MacAddress {
+    fn eq(&self, other: &Self) -> bool {
+        self.octets == other.octets
+    }
+}
+
+impl Eq for MacAddress {}
+
+impl std::hash::Hash for MacAddress {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.octets.hash(state);
+    }
+}
+
+impl PartialOrd for MacAddress {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MacAddress {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.octets.cmp(&other.octets)
+    }
 }
 
 impl MacAddress {
@@ -31,68 +80,180 @@ impl MacAddress {
     ///  - 00 11 22 AA BB CC
     ///  - 001122-AABBCC
     pub fn parse(address: &str) -> Result<Self, MacAddressError> {
-        // get raw address by removing known MAC delimiters,
-        // trimming any whitespace and transforming to lowercase
-        let raw = address
-            .trim()
-            .replace([':', '-', '.', ' '], "")
-            .to_lowercase();
+        let raw = normalize(address);
 
         // Valid MAC addresses have 12 chars, confirm the length == 12
         if raw.chars().count() != 12 {
             return Err(MacAddressError::InvalidLength(address.to_owned()));
         }
 
-        let mut eui48: Vec<u8> = Vec::new();
-        for c in raw.chars() {
-            match c {
-                // confirm address is made up of valid HEX chars
-                '0'..='9' | 'a'..='f' => eui48.push(match c.to_digit(16) {
-                    Some(i) => i as u8,
-                    None => return Err(MacAddressError::InvalidMac(address.to_owned())),
-                }),
-                _ => return Err(MacAddressError::InvalidMac(address.to_owned())),
-            };
+        let bytes = decode_hex(address, &raw)?;
+        let mut octets = [0u8; 6];
+        octets.copy_from_slice(&bytes);
+
+        Ok(Self { octets, vlan: None })
+    }
+
+    /// Parses a MAC address, additionally accepting the longer forms real
+    /// network tooling emits:
+    ///  - an explicit 802.1Q VLAN tag, eg `vlan100/00:11:22:aa:bb:cc` — this
+    ///    is the only way to attach a VLAN tag; there is no bare-hex form,
+    ///    since a VLAN+MAC byte layout is indistinguishable from an
+    ///    expanded EUI-64 address of the same length
+    ///  - an already-expanded 16-hex-digit EUI-64 address (with the `ff:fe`
+    ///    middle), eg `02-11-22-ff-fe-aa-bb-cc`, which recovers the original
+    ///    EUI-48 so that `eui64()` output round-trips back through the parser
+    ///
+    /// Plain 12-character EUI-48 input is still accepted, the same as
+    /// [`MacAddress::parse`].
+    pub fn parse_tagged(address: &str) -> Result<Self, MacAddressError> {
+        let trimmed = address.trim();
+
+        if let Some((vlan_part, mac_part)) = trimmed.split_once('/') {
+            let vlan = parse_vlan_tag(vlan_part, address)?;
+            let mac = MacAddress::parse(mac_part)?;
+            return Ok(Self {
+                vlan: Some(vlan),
+                ..mac
+            });
+        }
+
+        let raw = normalize(trimmed);
+        match raw.chars().count() {
+            12 => MacAddress::parse(address),
+            16 => {
+                let bytes = decode_hex(address, &raw)?;
+                if bytes[3] != 0xff || bytes[4] != 0xfe {
+                    return Err(MacAddressError::InvalidMac(address.to_owned()));
+                }
+
+                // Already-expanded EUI-64: reverse eui48_to_eui64 to
+                // recover the original EUI-48.
+                let octets = [
+                    bytes[0] ^ 0x02,
+                    bytes[1],
+                    bytes[2],
+                    bytes[5],
+                    bytes[6],
+                    bytes[7],
+                ];
+                Ok(Self { octets, vlan: None })
+            }
+            _ => Err(MacAddressError::InvalidLength(address.to_owned())),
+        }
+    }
+
+    /// Builds a MAC address from its six raw octets, eg as read straight
+    /// out of an Ethernet header.
+    pub fn from_bytes(octets: [u8; 6]) -> Self {
+        Self { octets, vlan: None }
+    }
+
+    /// Builds a MAC address from its six octets, passed individually.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(a: u8, b: u8, c: u8, d: u8, e: u8, f: u8) -> Self {
+        Self::from_bytes([a, b, c, d, e, f])
+    }
+
+    /// Returns the MAC address as its six raw octets.
+    pub fn as_bytes(&self) -> [u8; 6] {
+        self.octets
+    }
+
+    /// Maps an IPv4 multicast address to its destination MAC, per RFC 1112:
+    /// `01:00:5e` followed by the low 23 bits of the IP address (the top
+    /// bit of the 4th octet is cleared).
+    pub fn from_ipv4_multicast(ip: Ipv4Addr) -> Result<Self, MacAddressError> {
+        if !ip.is_multicast() {
+            return Err(MacAddressError::NotMulticast(ip.to_string()));
+        }
+
+        let o = ip.octets();
+        Ok(Self::from_bytes([
+            0x01,
+            0x00,
+            0x5e,
+            o[1] & 0x7f,
+            o[2],
+            o[3],
+        ]))
+    }
+
+    /// Maps an IPv6 multicast address to its destination MAC, per RFC 2464:
+    /// `33:33` followed by the low 32 bits of the IPv6 address.
+    pub fn from_ipv6_multicast(ip: Ipv6Addr) -> Result<Self, MacAddressError> {
+        if !ip.is_multicast() {
+            return Err(MacAddressError::NotMulticast(ip.to_string()));
         }
 
-        let eui64 = eui48_to_eui64(&eui48);
+        let o = ip.octets();
+        Ok(Self::from_bytes([0x33, 0x33, o[12], o[13], o[14], o[15]]))
+    }
+
+    /// Generates a random MAC address, forced to be unicast and locally
+    /// administered so it won't collide with a real hardware address.
+    /// Useful for minting synthetic MACs for VMs, taps, and containers.
+    #[cfg(feature = "rand")]
+    pub fn random() -> Self {
+        Self::random_from(&mut rand::thread_rng())
+    }
+
+    /// Like [`MacAddress::random`], but takes the first three octets from
+    /// `oui` and only randomizes the NIC portion. The Individual/Group and
+    /// Universal/Local bits of the first octet are still forced, so the
+    /// returned OUI can differ from `oui` in those two bits.
+    #[cfg(feature = "rand")]
+    pub fn random_with_oui(oui: [u8; 3]) -> Self {
+        let mut octets = [oui[0], oui[1], oui[2], 0, 0, 0];
+        rand::thread_rng().fill_bytes(&mut octets[3..6]);
+        force_unicast_local(&mut octets);
+        Self::from_bytes(octets)
+    }
 
-        Ok(Self { eui48, eui64 })
+    /// Like [`MacAddress::random`], but draws from the given RNG instead of
+    /// [`rand::thread_rng`], so callers (eg tests) can get deterministic
+    /// output.
+    #[cfg(feature = "rand")]
+    pub fn random_from<R: RngCore>(rng: &mut R) -> Self {
+        let mut octets = [0u8; 6];
+        rng.fill_bytes(&mut octets);
+        force_unicast_local(&mut octets);
+        Self::from_bytes(octets)
     }
 
     /// Returns the MAC address in the format `001122aabbcc`
     pub fn raw(&self) -> String {
-        format_mac(&self.eui48, "", 0)
+        format_mac(&self.octets, "", 1)
     }
 
     /// Returns the MAC address in the format `00-11-22-aa-bb-cc`
     pub fn eui(&self) -> String {
-        format_mac(&self.eui48, "-", 2)
+        format_mac(&self.octets, "-", 1)
     }
 
     /// Returns the MAC address in the format `00:11:22:aa:bb:cc`
     pub fn hex(&self) -> String {
-        format_mac(&self.eui48, ":", 2)
+        format_mac(&self.octets, ":", 1)
     }
 
     /// Returns the MAC address in the format `0011.22aa.bbcc`
     pub fn dot(&self) -> String {
-        format_mac(&self.eui48, ".", 4)
+        format_mac(&self.octets, ".", 2)
     }
 
-    /// Returns the octets representation of the MAC address  
+    /// Returns the octets representation of the MAC address
     /// in the format `["00", "11", "22", "aa", "bb", "cc"]`
     pub fn octets(&self) -> Vec<String> {
-        self.eui48
-            .chunks_exact(2)
-            .map(|i| format!("{:x}{:x}", i[0], i[1]))
-            .collect()
+        self.octets.iter().map(|b| format!("{b:02x}")).collect()
     }
 
     /// Returns the bits representation of the MAC address in the
     /// format `[0000", "0000", "0001", "0001", "0010", "0010", "1010", "1010", "1011", "1011", "1100", "1100"]`
     pub fn bits(&self) -> Vec<String> {
-        self.eui48.iter().map(|i| format!("{:04b}", i)).collect()
+        self.octets
+            .iter()
+            .flat_map(|b| [format!("{:04b}", b >> 4), format!("{:04b}", b & 0x0f)])
+            .collect()
     }
 
     /// Returns the binary representation of the MAC address in the
@@ -104,68 +265,167 @@ impl MacAddress {
     /// Returns the Organizationally Unique Identifier (OUI) portion of the
     /// MAC address in the format `001122`
     pub fn oui(&self) -> String {
-        self.eui48[0..=5]
-            .iter()
-            .map(|i| format!("{:x}", i))
-            .collect()
+        format_mac(&self.octets[0..3], "", 1)
     }
 
     /// Returns the Network Interface Card (NIC) portion of the
     /// MAC address in the format `aabbcc`
     pub fn nic(&self) -> String {
-        self.eui48[6..=11]
-            .iter()
-            .map(|i| format!("{:x}", i))
-            .collect()
+        format_mac(&self.octets[3..6], "", 1)
     }
 
     /// Broadcast MAC addresses are all `ffffffffffff`
     /// Returns true if MAC address is in the format `ffffffffffff`
     pub fn is_broadcast(&self) -> bool {
-        self.eui48 == vec![15; 12]
+        self.octets == [0xff; 6]
     }
 
-    /// Multicast MAC addresses start with 01005e
-    /// Returns true if the MAC address starts with `01005e`
+    /// Multicast (group) MAC addresses have the Individual/Group bit —
+    /// the least-significant bit of the first octet — set to 1.
+    /// Returns true for any group address other than broadcast.
     pub fn is_multicast(&self) -> bool {
-        self.eui48[0..=5] == vec![0, 1, 0, 0, 5, 14]
+        self.octets[0] & 1 == 1 && !self.is_broadcast()
+    }
+
+    /// Returns true if the MAC address is the IPv4 multicast OUI, ie it
+    /// starts with `01:00:5e`.
+    pub fn is_ipv4_multicast(&self) -> bool {
+        self.octets[0..3] == [0x01, 0x00, 0x5e]
     }
 
-    /// Returns true if the MAC address is a unicast address
+    /// Returns true if the MAC address is the IPv6 multicast prefix, ie it
+    /// starts with `33:33`.
+    pub fn is_ipv6_multicast(&self) -> bool {
+        self.octets[0..2] == [0x33, 0x33]
+    }
+
+    /// Returns true if the MAC address is a unicast address, ie the
+    /// Individual/Group bit is not set.
     pub fn is_unicast(&self) -> bool {
         !(self.is_broadcast() || self.is_multicast())
     }
 
     /// Universal (U) or Global MAC addresses have their 7th bit set to 0.
     pub fn is_universal(&self) -> bool {
-        (self.eui48[1] >> 1) & 1 == 0
+        (self.octets[0] >> 1) & 1 == 0
     }
 
     /// Local (L) MAC addresses have their 7th bit set to 1.
     pub fn is_local(&self) -> bool {
-        (self.eui48[1] >> 1) & 1 == 1
+        (self.octets[0] >> 1) & 1 == 1
     }
 
     /// Returns an EUI-64 address from the EUI-48 address in
     /// the format `00-11-22-ff-fe-aa-bb-cc`
     pub fn eui64(&self) -> String {
-        format_mac(&self.eui64, "-", 2)
+        format_mac(&eui48_to_eui64(&self.octets), "-", 1)
     }
 
     /// Returns an IPv6 Link Local address from the EUI-48 address in
     /// the format `fe80::0011:22ff:feaa:bbcc`
     pub fn ipv6_link_local(&self) -> String {
-        format!("fe80::{}", format_mac(&self.eui64, ":", 4))
+        format!(
+            "fe80::{}",
+            format_mac(&eui48_to_eui64(&self.octets), ":", 2)
+        )
+    }
+
+    /// Returns the 802.1Q VLAN ID this address was tagged with, if it was
+    /// parsed via [`MacAddress::parse_tagged`] with a VLAN tag present.
+    pub fn vlan(&self) -> Option<u16> {
+        self.vlan
+    }
+
+    /// Returns the MAC address in its tagged form, eg `vlan100/00:11:22:aa:bb:cc`
+    /// if a VLAN ID is present, or plain `hex()` otherwise.
+    pub fn tagged(&self) -> String {
+        match self.vlan {
+            Some(id) => format!("vlan{id}/{}", self.hex()),
+            None => self.hex(),
+        }
     }
 }
 
 impl std::fmt::Display for MacAddress {
+    /// Displays the MAC address in the canonical colon-hex format, eg
+    /// `00:11:22:aa:bb:cc`. Use [`MacAddress::eui64`] for the EUI-64 form.
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.hex())
+    }
+}
+
+impl std::fmt::LowerHex for MacAddress {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "EUI-48: {}\nEUI-64: {}", self.eui(), self.eui64())
+        write!(f, "{}", self.raw())
+    }
+}
+
+impl std::fmt::UpperHex for MacAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.raw().to_uppercase())
+    }
+}
+
+impl std::str::FromStr for MacAddress {
+    type Err = MacAddressError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        MacAddress::parse(address)
+    }
+}
+
+impl TryFrom<[u8; 6]> for MacAddress {
+    type Error = MacAddressError;
+
+    fn try_from(bytes: [u8; 6]) -> Result<Self, Self::Error> {
+        Ok(MacAddress::from_bytes(bytes))
+    }
+}
+
+impl TryFrom<&[u8]> for MacAddress {
+    type Error = MacAddressError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes: [u8; 6] = bytes
+            .try_into()
+            .map_err(|_| MacAddressError::InvalidLength(format!("{bytes:?}")))?;
+        Ok(MacAddress::from_bytes(bytes))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for MacAddress {
+    /// Serializes as the canonical colon-hex string, eg `00:11:22:aa:bb:cc`.
+    /// Use the wrapper types in [`serde_support`] if you need a different
+    /// on-the-wire format.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.hex())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for MacAddress {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let raw = String::deserialize(deserializer)?;
+        MacAddress::parse(&raw).map_err(serde::de::Error::custom)
     }
 }
 
-// Converts an EUI-48 address to an EUI-46 address.
+// Clears the Individual/Group bit and sets the Universal/Local bit on the
+// first octet, forcing the address to be unicast and locally administered.
+#[cfg(feature = "rand")]
+fn force_unicast_local(octets: &mut [u8; 6]) {
+    octets[0] &= !0x01;
+    octets[0] |= 0x02;
+}
+
+// Converts an EUI-48 address to an EUI-64 address.
 // A converted EUI-64 address has the  Universal/Local (U/L)
 // bit inverted. The U/L bit is the 7th but in the first octet.
 // Reference RFC: http://www.faqs.org/rfcs/rfc2373.html
@@ -185,46 +445,78 @@ impl std::fmt::Display for MacAddress {
 //
 // 5) Convert these first eight bits back into hex.
 // 00000010 -> 02, which yields an EUI-64 address of 0215:2bff:fee4:9b60
-fn eui48_to_eui64(eui48: &[u8]) -> Vec<u8> {
-    vec![
-        eui48[0],
-        eui48[1] ^ 0x02, // reverses the 7th bit from the 1st octect
-        eui48[2],
-        eui48[3],
-        eui48[4],
-        eui48[5],
-        15,
-        15,
-        15,
-        14,
-        eui48[6],
-        eui48[7],
-        eui48[8],
-        eui48[9],
-        eui48[10],
-        eui48[11],
+fn eui48_to_eui64(octets: &[u8; 6]) -> [u8; 8] {
+    [
+        octets[0] ^ 0x02, // reverses the 7th bit from the 1st octet
+        octets[1],
+        octets[2],
+        0xff,
+        0xfe,
+        octets[3],
+        octets[4],
+        octets[5],
     ]
 }
 
+// Strips known MAC delimiters, trims whitespace, and lowercases the input.
+fn normalize(address: &str) -> String {
+    address
+        .trim()
+        .replace([':', '-', '.', ' '], "")
+        .to_lowercase()
+}
+
+// Decodes a delimiter-free lowercase hex string into bytes, two hex chars
+// per byte. `raw` must have an even length; `address` is only used to
+// build error messages against the original input, delimiters included.
+fn decode_hex(address: &str, raw: &str) -> Result<Vec<u8>, MacAddressError> {
+    let mut nibbles: Vec<u8> = Vec::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '0'..='9' | 'a'..='f' => nibbles.push(match c.to_digit(16) {
+                Some(i) => i as u8,
+                None => return Err(MacAddressError::InvalidMac(address.to_owned())),
+            }),
+            _ => return Err(MacAddressError::InvalidMac(address.to_owned())),
+        };
+    }
+
+    Ok(nibbles
+        .chunks_exact(2)
+        .map(|chunk| (chunk[0] << 4) | chunk[1])
+        .collect())
+}
+
+// Parses a `vlanNNN` tag, eg `vlan100` -> `100`.
+fn parse_vlan_tag(part: &str, address: &str) -> Result<u16, MacAddressError> {
+    let lower = part.trim().to_lowercase();
+    let id = lower
+        .strip_prefix("vlan")
+        .and_then(|digits| digits.parse::<u16>().ok())
+        .ok_or_else(|| MacAddressError::InvalidMac(address.to_owned()))?;
+
+    validate_vlan_id(id, address)
+}
+
+// 802.1Q VLAN IDs are a 12-bit field, so valid values are 0..=4095.
+fn validate_vlan_id(id: u16, address: &str) -> Result<u16, MacAddressError> {
+    if id > 4095 {
+        return Err(MacAddressError::InvalidMac(address.to_owned()));
+    }
+    Ok(id)
+}
+
 /// Format a MAC address into the desired format
 /// examples:
-///  format_mac(&self.eui48, "", 0) // 001122aabbcc
-///  format_mac(&self.eui48, "-", 2) // 00-11-22-aa-bb-cc
-///  format_mac(&self.eui48, ".", 4) // 0011.22aa.bbcc
-fn format_mac(mac: &[u8], delimiter: &str, chunks: u8) -> String {
-    match chunks {
-        4 => mac
-            .chunks_exact(4)
-            .map(|c| format!("{:x}{:x}{:x}{:x}", c[0], c[1], c[2], c[3]))
-            .collect::<Vec<String>>()
-            .join(delimiter),
-        2 => mac
-            .chunks_exact(2)
-            .map(|c| format!("{:x}{:x}", c[0], c[1]))
-            .collect::<Vec<String>>()
-            .join(delimiter),
-        _ => mac.iter().map(|c| format!("{:x}", c)).collect(),
-    }
+///  format_mac(&self.octets, "", 1) // 001122aabbcc
+///  format_mac(&self.octets, "-", 1) // 00-11-22-aa-bb-cc
+///  format_mac(&self.octets, ".", 2) // 0011.22aa.bbcc
+fn format_mac(bytes: &[u8], delimiter: &str, group_bytes: usize) -> String {
+    bytes
+        .chunks_exact(group_bytes)
+        .map(|c| c.iter().map(|b| format!("{b:02x}")).collect::<String>())
+        .collect::<Vec<String>>()
+        .join(delimiter)
 }
 
 #[cfg(test)]
@@ -350,7 +642,11 @@ mod tests {
 
     #[test]
     fn is_multicast_mac() {
-        let test_cases = vec![("01005eaabbcc", true), ("001122aabbcc", false)];
+        let test_cases = vec![
+            ("01005eaabbcc", true),
+            ("001122aabbcc", false),
+            ("ffffffffffff", false),
+        ];
         for tc in test_cases {
             let mac = MacAddress::parse(tc.0).unwrap();
             assert!(mac.is_multicast() == tc.1)
@@ -363,6 +659,7 @@ mod tests {
             ("001122aabbcc", true),
             ("01005eaabbcc", false),
             ("ffffffffffff", false),
+            ("3333000000ff", false),
         ];
         for tc in test_cases {
             let mac = MacAddress::parse(tc.0).unwrap();
@@ -370,6 +667,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_ipv4_multicast_mac() {
+        let test_cases = vec![
+            ("01005eaabbcc", true),
+            ("3333000000ff", false),
+            ("001122aabbcc", false),
+        ];
+        for tc in test_cases {
+            let mac = MacAddress::parse(tc.0).unwrap();
+            assert!(mac.is_ipv4_multicast() == tc.1)
+        }
+    }
+
+    #[test]
+    fn is_ipv6_multicast_mac() {
+        let test_cases = vec![
+            ("3333000000ff", true),
+            ("01005eaabbcc", false),
+            ("001122aabbcc", false),
+        ];
+        for tc in test_cases {
+            let mac = MacAddress::parse(tc.0).unwrap();
+            assert!(mac.is_ipv6_multicast() == tc.1)
+        }
+    }
+
+    #[test]
+    fn from_ipv4_multicast_mac() {
+        let mac = MacAddress::from_ipv4_multicast(Ipv4Addr::new(239, 255, 0, 1)).unwrap();
+        assert_eq!(mac.hex(), "01:00:5e:7f:00:01".to_owned());
+    }
+
+    #[test]
+    fn from_ipv4_multicast_rejects_non_multicast_ip() {
+        let err = MacAddress::from_ipv4_multicast(Ipv4Addr::new(10, 0, 0, 1)).unwrap_err();
+        assert_eq!(MacAddressError::NotMulticast("10.0.0.1".to_owned()), err);
+    }
+
+    #[test]
+    fn from_ipv6_multicast_mac() {
+        let mac =
+            MacAddress::from_ipv6_multicast(Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0xaabb, 0xccdd))
+                .unwrap();
+        assert_eq!(mac.hex(), "33:33:aa:bb:cc:dd".to_owned());
+    }
+
+    #[test]
+    fn from_ipv6_multicast_rejects_non_multicast_ip() {
+        let err =
+            MacAddress::from_ipv6_multicast(Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1)).unwrap_err();
+        assert_eq!(MacAddressError::NotMulticast("::1".to_owned()), err);
+    }
+
     #[test]
     fn is_universal_mac() {
         let test_cases = vec![
@@ -427,4 +777,122 @@ mod tests {
             "fe80::0011:22ff:feaa:bbcc".to_owned()
         );
     }
+
+    #[test]
+    fn mac_address_is_copy_and_hashable() {
+        use std::collections::HashMap;
+
+        let mac = MacAddress::parse("001122aabbcc").unwrap();
+        let mut table: HashMap<MacAddress, &str> = HashMap::new();
+        table.insert(mac, "port1");
+
+        assert_eq!(table.get(&mac), Some(&"port1"));
+    }
+
+    #[test]
+    fn equality_and_hashing_ignore_vlan() {
+        use std::collections::HashMap;
+
+        let untagged = MacAddress::parse("001122aabbcc").unwrap();
+        let tagged = MacAddress::parse_tagged("vlan100/00:11:22:aa:bb:cc").unwrap();
+
+        assert_eq!(untagged, tagged);
+
+        let mut table: HashMap<MacAddress, &str> = HashMap::new();
+        table.insert(untagged, "port1");
+        assert_eq!(table.get(&tagged), Some(&"port1"));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_mac_is_unicast_and_local() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mac = MacAddress::random_from(&mut rng);
+        assert!(mac.is_unicast());
+        assert!(mac.is_local());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_mac_is_deterministic_from_seed() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mac_a = MacAddress::random_from(&mut StdRng::seed_from_u64(42));
+        let mac_b = MacAddress::random_from(&mut StdRng::seed_from_u64(42));
+        assert_eq!(mac_a, mac_b);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_mac_with_oui_keeps_oui_apart_from_forced_bits() {
+        // The Individual/Group and Universal/Local bits live in the OUI's
+        // first octet, so they get forced even when the OUI is caller-supplied.
+        let mac = MacAddress::random_with_oui([0x00, 0x11, 0x22]);
+        assert_eq!(mac.oui(), "021122".to_owned());
+        assert!(mac.is_unicast());
+        assert!(mac.is_local());
+    }
+
+    #[test]
+    fn parse_tagged_plain_mac() {
+        let mac = MacAddress::parse_tagged("00:11:22:aa:bb:cc").unwrap();
+        assert_eq!(mac.hex(), "00:11:22:aa:bb:cc".to_owned());
+        assert_eq!(mac.vlan(), None);
+    }
+
+    #[test]
+    fn parse_tagged_vlan_slash_form() {
+        let mac = MacAddress::parse_tagged("vlan100/00:11:22:aa:bb:cc").unwrap();
+        assert_eq!(mac.hex(), "00:11:22:aa:bb:cc".to_owned());
+        assert_eq!(mac.vlan(), Some(100));
+        assert_eq!(mac.tagged(), "vlan100/00:11:22:aa:bb:cc".to_owned());
+    }
+
+    #[test]
+    fn parse_tagged_bare_16_hex_non_eui64_is_rejected() {
+        // A bare 16-hex-digit input that isn't a valid expanded EUI-64 (no
+        // ff:fe middle) is rejected rather than guessed at as a VLAN+MAC
+        // layout, since the two are indistinguishable by shape alone.
+        let address = "0064001122aabbcc";
+        let err = MacAddress::parse_tagged(address).unwrap_err();
+        assert_eq!(MacAddressError::InvalidMac(address.to_owned()), err);
+    }
+
+    #[test]
+    fn parse_tagged_recovers_eui64_round_trip() {
+        let mac = MacAddress::parse("001122aabbcc").unwrap();
+        let eui64 = mac.eui64();
+        let recovered = MacAddress::parse_tagged(&eui64).unwrap();
+        assert_eq!(recovered, mac);
+        assert_eq!(recovered.vlan(), None);
+    }
+
+    #[test]
+    fn parse_tagged_invalid_length() {
+        let address = "aabb";
+        let err = MacAddress::parse_tagged(address).unwrap_err();
+        assert_eq!(MacAddressError::InvalidLength(address.to_owned()), err);
+    }
+
+    #[test]
+    fn parse_tagged_invalid_vlan_prefix() {
+        let address = "bogus100/00:11:22:aa:bb:cc";
+        let err = MacAddress::parse_tagged(address).unwrap_err();
+        assert_eq!(MacAddressError::InvalidMac(address.to_owned()), err);
+    }
+
+    #[test]
+    fn parse_tagged_rejects_out_of_range_vlan() {
+        let address = "vlan9999/00:11:22:aa:bb:cc";
+        let err = MacAddress::parse_tagged(address).unwrap_err();
+        assert_eq!(MacAddressError::InvalidMac(address.to_owned()), err);
+    }
+
+    #[test]
+    fn tagged_without_vlan_is_plain_hex() {
+        let mac = MacAddress::parse("001122aabbcc").unwrap();
+        assert_eq!(mac.tagged(), "00:11:22:aa:bb:cc".to_owned());
+    }
 }
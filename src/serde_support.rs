@@ -0,0 +1,76 @@
+//! Newtype wrappers around [`MacAddress`] that pick a specific on-the-wire
+//! string format for serde, for callers who want something other than the
+//! default `hex()` format used by `MacAddress`'s own `Serialize`/`Deserialize`
+//! impls.
+
+use crate::MacAddress;
+
+macro_rules! mac_address_serde_wrapper {
+    ($name:ident, $format:ident, $doc:literal) => {
+        #[doc = $doc]
+        #[derive(Debug)]
+        pub struct $name(pub MacAddress);
+
+        impl std::ops::Deref for $name {
+            type Target = MacAddress;
+
+            fn deref(&self) -> &Self::Target {
+                &self.0
+            }
+        }
+
+        impl From<MacAddress> for $name {
+            fn from(mac: MacAddress) -> Self {
+                Self(mac)
+            }
+        }
+
+        impl From<$name> for MacAddress {
+            fn from(wrapper: $name) -> Self {
+                wrapper.0
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                serializer.serialize_str(&self.0.$format())
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                let raw = String::deserialize(deserializer)?;
+                MacAddress::parse(&raw)
+                    .map(Self)
+                    .map_err(serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+mac_address_serde_wrapper!(
+    MacAddressRaw,
+    raw,
+    "Serializes/deserializes a `MacAddress` in the `raw()` format, eg `001122aabbcc`."
+);
+mac_address_serde_wrapper!(
+    MacAddressEui,
+    eui,
+    "Serializes/deserializes a `MacAddress` in the `eui()` format, eg `00-11-22-aa-bb-cc`."
+);
+mac_address_serde_wrapper!(
+    MacAddressHex,
+    hex,
+    "Serializes/deserializes a `MacAddress` in the `hex()` format, eg `00:11:22:aa:bb:cc`."
+);
+mac_address_serde_wrapper!(
+    MacAddressDot,
+    dot,
+    "Serializes/deserializes a `MacAddress` in the `dot()` format, eg `0011.22aa.bbcc`."
+);